@@ -2,7 +2,13 @@
 
 use dmsdk_ffi::dmConfigFile;
 use libc::c_void;
+use serde::de::{self, DeserializeOwned, IntoDeserializer, MapAccess, Visitor};
+use std::collections::HashMap;
 use std::ffi::{c_char, CStr, CString};
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Mutex;
 
 #[doc(hidden)]
 pub type RawConfigFile = dmConfigFile::HConfig;
@@ -93,12 +99,524 @@ pub fn get_float(config: ConfigFile, key: &str, default_value: f32) -> f32 {
     unsafe { dmConfigFile::GetFloat(config.into(), key.as_ptr(), default_value) }
 }
 
+/// Gets the corresponding config value as a bool.
+///
+/// `0`/`1`, `true`/`false` and `yes`/`no` (case-insensitive) are accepted;
+/// `default_value` is returned if the key is missing or holds anything else.
+///
+/// # Examples
+/// ```
+/// # const LOG_DOMAIN: &str = "DOCTEST";
+/// use dmsdk::*;
+///
+/// fn app_init(params: dmextension::AppParams) -> dmextension::Result {
+///     let fullscreen = dmconfigfile::get_bool(params.config, "display.fullscreen", false);
+///     dmlog::info!("Fullscreen is: {fullscreen}");
+///
+///     dmextension::Result::Ok
+/// }
+/// ```
+pub fn get_bool(config: ConfigFile, key: &str, default_value: bool) -> bool {
+    match probe(config, key) {
+        Some(raw) => match raw.trim().to_ascii_lowercase().as_str() {
+            "1" | "true" | "yes" => true,
+            "0" | "false" | "no" => false,
+            _ => default_value,
+        },
+        None => default_value,
+    }
+}
+
+/// Gets the corresponding config value as a list of strings.
+///
+/// The raw value is read with [`get_string`], optional surrounding `[` `]` are
+/// stripped, and the remainder is split on commas with whitespace and optional
+/// surrounding quotes trimmed from each element. `default_value` is used if the
+/// key isn't found.
+///
+/// # Examples
+/// ```
+/// # const LOG_DOMAIN: &str = "DOCTEST";
+/// use dmsdk::*;
+///
+/// fn app_init(params: dmextension::AppParams) -> dmextension::Result {
+///     let langs = dmconfigfile::get_list(params.config, "project.supported_languages", &["en"]);
+///     dmlog::info!("Supported languages: {langs:?}");
+///
+///     dmextension::Result::Ok
+/// }
+/// ```
+pub fn get_list(config: ConfigFile, key: &str, default_value: &[&str]) -> Vec<String> {
+    match probe(config, key) {
+        Some(raw) => parse_list(&raw),
+        None => default_value.iter().map(|s| (*s).to_owned()).collect(),
+    }
+}
+
+/// Like [`get_list`], but parses each element into `T` via [`FromStr`].
+///
+/// Elements that fail to parse are skipped.
+///
+/// # Examples
+/// ```
+/// # const LOG_DOMAIN: &str = "DOCTEST";
+/// use dmsdk::*;
+///
+/// fn app_init(params: dmextension::AppParams) -> dmextension::Result {
+///     let weights: Vec<f32> = dmconfigfile::get_list_of(params.config, "physics.layer_weights", &["1.0"]);
+///     dmlog::info!("Layer weights: {weights:?}");
+///
+///     dmextension::Result::Ok
+/// }
+/// ```
+pub fn get_list_of<T: FromStr>(config: ConfigFile, key: &str, default_value: &[&str]) -> Vec<T> {
+    let raw = match probe(config, key) {
+        Some(raw) => parse_list(&raw),
+        None => default_value.iter().map(|s| (*s).to_owned()).collect(),
+    };
+    raw.iter().filter_map(|elem| elem.parse().ok()).collect()
+}
+
+/// Splits a raw `[a, b, c]` or `a, b, c` list value into trimmed, unquoted elements.
+fn parse_list(raw: &str) -> Vec<String> {
+    let trimmed = raw.trim();
+    let inner = trimmed
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .unwrap_or(trimmed)
+        .trim();
+    if inner.is_empty() {
+        return Vec::new();
+    }
+    inner
+        .split(',')
+        .map(|elem| {
+            let elem = elem.trim();
+            let elem = elem
+                .strip_prefix('"')
+                .and_then(|s| s.strip_suffix('"'))
+                .or_else(|| elem.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')))
+                .unwrap_or(elem);
+            elem.to_owned()
+        })
+        .collect()
+}
+
+/// Errors that can occur while deserializing a config section with [`from_config`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A struct field had no matching key in the config file. Defold returns the
+    /// supplied default for missing keys, so these are detected by probing with two
+    /// distinct sentinel defaults.
+    MissingKey(String),
+    /// A free-form error raised by serde while deserializing.
+    Message(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::MissingKey(key) => write!(f, "missing config key '{key}'"),
+            Error::Message(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Message(msg.to_string())
+    }
+}
+
+// Two distinct defaults handed to `GetString` for the same key: if both come back
+// unchanged the key is genuinely absent, otherwise the returned value is real.
+const SENTINEL_A: &str = "\u{1}__dmconfigfile_absent_a__";
+const SENTINEL_B: &str = "\u{1}__dmconfigfile_absent_b__";
+
+fn probe(config: ConfigFile, key: &str) -> Option<String> {
+    let a = get_string(config, key, SENTINEL_A);
+    if a != SENTINEL_A {
+        return Some(a);
+    }
+    let b = get_string(config, key, SENTINEL_B);
+    if b != SENTINEL_B {
+        return Some(b);
+    }
+    None
+}
+
+/// Deserializes a whole section of the config file into a struct in one call.
+///
+/// Each field of `T` is read as `"{section}.{field}"`, dispatching to
+/// [`get_string`]/[`get_int`]/[`get_float`] depending on the type serde asks for.
+/// A non-[`Option`] field whose key is absent yields [`Error::MissingKey`]; an
+/// [`Option`] field maps a missing key to [`None`].
+///
+/// # Examples
+/// ```
+/// # const LOG_DOMAIN: &str = "DOCTEST";
+/// use dmsdk::*;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Display {
+///     width: i32,
+///     height: i32,
+///     title: String,
+/// }
+///
+/// fn app_init(params: dmextension::AppParams) -> dmextension::Result {
+///     let display: Display = dmconfigfile::from_config(params.config, "display").unwrap();
+///     dmlog::info!("Window is {}x{}", display.width, display.height);
+///
+///     dmextension::Result::Ok
+/// }
+/// ```
+pub fn from_config<T: DeserializeOwned>(config: ConfigFile, section: &str) -> Result<T, Error> {
+    T::deserialize(SectionDeserializer { config, section })
+}
+
+struct SectionDeserializer<'a> {
+    config: ConfigFile,
+    section: &'a str,
+}
+
+impl<'de, 'a> de::Deserializer<'de> for SectionDeserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_map(SectionMap {
+            config: self.config,
+            section: self.section,
+            fields: fields.iter(),
+            value: None,
+        })
+    }
+
+    fn deserialize_any<V>(self, _visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        Err(Error::Message(
+            "from_config can only deserialize into a struct".to_owned(),
+        ))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map enum
+        identifier ignored_any
+    }
+}
+
+struct SectionMap<'a> {
+    config: ConfigFile,
+    section: &'a str,
+    fields: std::slice::Iter<'static, &'static str>,
+    value: Option<String>,
+}
+
+impl<'de, 'a> MapAccess<'de> for SectionMap<'a> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Error>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        match self.fields.next() {
+            Some(field) => {
+                self.value = Some(format!("{}.{}", self.section, field));
+                seed.deserialize((*field).into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let key = self.value.take().expect("next_value_seed before next_key_seed");
+        seed.deserialize(ValueDeserializer {
+            config: self.config,
+            key,
+        })
+    }
+}
+
+struct ValueDeserializer {
+    config: ConfigFile,
+    key: String,
+}
+
+impl ValueDeserializer {
+    fn require(&self) -> Result<String, Error> {
+        probe(self.config, &self.key).ok_or_else(|| Error::MissingKey(self.key.clone()))
+    }
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer {
+    type Error = Error;
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_string(self.require()?)
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_string(self.require()?)
+    }
+
+    fn deserialize_i64<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.require()?;
+        visitor.visit_i64(get_int(self.config, &self.key, 0) as i64)
+    }
+
+    fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.require()?;
+        visitor.visit_f64(get_float(self.config, &self.key, 0.0) as f64)
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.require()?;
+        visitor.visit_bool(get_int(self.config, &self.key, 0) != 0)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match probe(self.config, &self.key) {
+            Some(_) => visitor.visit_some(self),
+            None => visitor.visit_none(),
+        }
+    }
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        // Without a type hint we can only offer the raw string value.
+        visitor.visit_string(self.require()?)
+    }
+
+    serde::forward_to_deserialize_any! {
+        char bytes byte_buf unit unit_struct newtype_struct seq tuple tuple_struct
+        map struct enum identifier ignored_any
+    }
+
+    fn deserialize_i8<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_i16<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_i32<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u8<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u16<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u32<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u64<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_f64(visitor)
+    }
+}
+
+/// Errors that can occur while loading an [`Overlay`].
+#[derive(Debug)]
+pub enum OverlayError {
+    /// The overlay file could not be read.
+    Io(std::io::Error),
+    /// The overlay file was not valid TOML.
+    Parse(toml::de::Error),
+}
+
+impl fmt::Display for OverlayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OverlayError::Io(err) => write!(f, "failed to read overlay: {err}"),
+            OverlayError::Parse(err) => write!(f, "failed to parse overlay: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for OverlayError {}
+
+impl From<std::io::Error> for OverlayError {
+    fn from(err: std::io::Error) -> Self {
+        OverlayError::Io(err)
+    }
+}
+
+impl From<toml::de::Error> for OverlayError {
+    fn from(err: toml::de::Error) -> Self {
+        OverlayError::Parse(err)
+    }
+}
+
+/// A flat set of config overrides loaded from an external file.
+///
+/// Loading an overlay once in `plugin_create` lets a plugin's getters override
+/// compiled `game.project` values at runtime — handy for dev or CI overrides. Each
+/// getter delegates to [`get_str`](Overlay::get_str) / [`get_int`](Overlay::get_int)
+/// / [`get_float`](Overlay::get_float) and returns the result straight through:
+/// [`Some`] overrides the value, [`None`] falls through to the next plugin.
+///
+/// The TOML is flattened into dotted `section.key` keys, so
+/// ```toml
+/// [display]
+/// width = 1280
+/// ```
+/// is reached as `overlay.get_int("display.width")`.
+///
+/// # Examples
+/// ```
+/// # const LOG_DOMAIN: &str = "DOCTEST";
+/// use dmsdk::*;
+///
+/// fn get_int(config: dmconfigfile::ConfigFile, key: &str, default_value: i32) -> Option<i32> {
+///     let overlay = dmconfigfile::Overlay::from_toml_path("overrides.toml").ok()?;
+///     overlay.get_int(key)
+/// }
+/// ```
+pub struct Overlay {
+    values: HashMap<String, String>,
+}
+
+impl Overlay {
+    /// Loads a flat `section.key = value` override map from a TOML file.
+    pub fn from_toml_path<P: AsRef<Path>>(path: P) -> Result<Self, OverlayError> {
+        let text = std::fs::read_to_string(path)?;
+        let root: toml::Value = toml::from_str(&text)?;
+        let mut values = HashMap::new();
+        flatten(None, &root, &mut values);
+        Ok(Self { values })
+    }
+
+    /// Returns the overriding string for `key`, or [`None`] if the overlay doesn't set it.
+    pub fn get_str(&self, key: &str) -> Option<String> {
+        self.values.get(key).cloned()
+    }
+
+    /// Returns the overriding i32 for `key`, coercing the stored value the way Defold would.
+    pub fn get_int(&self, key: &str) -> Option<i32> {
+        self.values.get(key)?.trim().parse().ok()
+    }
+
+    /// Returns the overriding f32 for `key`, coercing the stored value the way Defold would.
+    pub fn get_float(&self, key: &str) -> Option<f32> {
+        self.values.get(key)?.trim().parse().ok()
+    }
+}
+
+/// Flattens nested TOML tables into dotted keys with scalar values stringified.
+fn flatten(prefix: Option<&str>, value: &toml::Value, out: &mut HashMap<String, String>) {
+    match value {
+        toml::Value::Table(table) => {
+            for (k, v) in table {
+                let key = match prefix {
+                    Some(prefix) => format!("{prefix}.{k}"),
+                    None => k.clone(),
+                };
+                flatten(Some(&key), v, out);
+            }
+        }
+        scalar => {
+            if let Some(key) = prefix {
+                let text = match scalar {
+                    toml::Value::String(s) => s.clone(),
+                    toml::Value::Integer(i) => i.to_string(),
+                    toml::Value::Float(f) => f.to_string(),
+                    toml::Value::Boolean(b) => b.to_string(),
+                    other => other.to_string(),
+                };
+                out.insert(key.to_owned(), text);
+            }
+        }
+    }
+}
+
 /// Callback function called during the config plugin lifecycle.
 pub type PluginLifecycle = fn(ConfigFile);
 /// Function used to provide config values.
 pub type PluginGetter<T> = fn(ConfigFile, &str, T) -> Option<T>;
 #[doc(hidden)]
 pub type StringGetter = fn(ConfigFile, &str, &str) -> Option<String>;
+
+/// Creates a context-carrying config plugin, returning the boxed state stored
+/// alongside the descriptor and handed to every getter. See
+/// [`declare_configfile_extension_with_context`].
+pub type PluginCreateCtx<T> = fn(ConfigFile) -> Box<T>;
+/// Lifecycle callback for a context-carrying config plugin.
+pub type PluginLifecycleCtx<T> = fn(&mut T, ConfigFile);
+/// Function used to provide config values for a context-carrying config plugin.
+pub type PluginGetterCtx<T, V> = fn(&mut T, ConfigFile, &str, V) -> Option<V>;
+#[doc(hidden)]
+pub type StringGetterCtx<T> = fn(&mut T, ConfigFile, &str, &str) -> Option<String>;
 #[doc(hidden)]
 pub type RawPluginLifecycle = unsafe extern "C" fn(dmConfigFile::HConfig);
 #[doc(hidden)]
@@ -201,6 +719,93 @@ macro_rules! declare_plugin_string_getter {
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! declare_plugin_getter_ctx {
+    ($symbol:ident, $context_static:ident, $context:ty, $option:expr, $type:ident) => {
+        #[no_mangle]
+        unsafe extern "C" fn $symbol(
+            config: dmconfigfile::RawConfigFile,
+            key: *const core::ffi::c_char,
+            default_value: $type,
+            out: *mut $type,
+        ) -> bool {
+            let func: Option<dmconfigfile::PluginGetterCtx<$context, $type>> = $option;
+            let ptr = $context_static.load(core::sync::atomic::Ordering::SeqCst);
+            if ptr.is_null() {
+                return false;
+            }
+            let context = &mut *(ptr as *mut $context);
+            if let Some(func) = func {
+                let key = core::ffi::CStr::from_ptr(key)
+                    .to_str()
+                    .expect("Invalid UTF-8 sequence in key!");
+                if let Some(value) = func(context, config.into(), key, default_value) {
+                    out.write(value);
+                    true
+                } else {
+                    false
+                }
+            } else {
+                false
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! declare_plugin_string_getter_ctx {
+    ($symbol:ident, $context_static:ident, $context:ty, $option:expr) => {
+        #[no_mangle]
+        unsafe extern "C" fn $symbol(
+            config: dmconfigfile::RawConfigFile,
+            key: *const core::ffi::c_char,
+            default_value: *const core::ffi::c_char,
+            out: *mut *const core::ffi::c_char,
+        ) -> bool {
+            let func: Option<dmconfigfile::StringGetterCtx<$context>> = $option;
+            let ptr = $context_static.load(core::sync::atomic::Ordering::SeqCst);
+            if ptr.is_null() {
+                return false;
+            }
+            let context = &mut *(ptr as *mut $context);
+            if let Some(func) = func {
+                let key = core::ffi::CStr::from_ptr(key).to_str();
+                if key.is_err() {
+                    dmlog::error!("Invalid UTF-8 sequence in key!");
+                    return false;
+                }
+
+                let default_value = if default_value.is_null() {
+                    ""
+                } else {
+                    match core::ffi::CStr::from_ptr(default_value).to_str() {
+                        Ok(str) => str,
+                        Err(_) => {
+                            dmlog::error!("Invalid UTF-8 sequence in default value!");
+                            return false;
+                        }
+                    }
+                };
+
+                if let Some(value) = func(context, config.into(), key.unwrap(), default_value) {
+                    let cstr =
+                        std::ffi::CString::new(value).expect("Unexpected null in return value!");
+
+                    let boxed_str = Box::new(cstr);
+                    out.write(Box::leak(boxed_str).as_ptr());
+                    true
+                } else {
+                    false
+                }
+            } else {
+                false
+            }
+        }
+    };
+}
+
 /// Equivalent to `DM_DECLARE_CONFIGFILE_EXTENSION` in regular C++ extensions.
 ///
 /// Each `get` function is called whenever a config value is requested from Lua or C++.
@@ -248,9 +853,34 @@ macro_rules! declare_plugin_string_getter {
 ///     Some(get_float)
 /// );
 /// ```
+///
+/// An optional trailing metadata block records the plugin's version and provenance,
+/// surfaced through [`registered_plugins`]:
+/// ```
+/// # use dmsdk::*;
+/// # fn get_string(config: dmconfigfile::ConfigFile, key: &str, default_value: &str) -> Option<String> { None }
+/// declare_configfile_extension!(
+///     MY_CONFIG_PLUGIN,
+///     None,
+///     None,
+///     Some(get_string),
+///     None,
+///     None,
+///     {
+///         version: "1.0.0",
+///         license: "MIT",
+///         description: "Overrides the project title",
+///     }
+/// );
+/// ```
 #[macro_export]
 macro_rules! declare_configfile_extension {
-    ($symbol:ident, $create:expr, $destroy:expr, $get_string:expr, $get_int:expr, $get_float:expr) => {
+    ($symbol:ident, $create:expr, $destroy:expr, $get_string:expr, $get_int:expr, $get_float:expr $(,)?) => {
+        declare_configfile_extension!(
+            $symbol, $create, $destroy, $get_string, $get_int, $get_float, {}
+        );
+    };
+    ($symbol:ident, $create:expr, $destroy:expr, $get_string:expr, $get_int:expr, $get_float:expr, { $($field:ident : $value:expr),* $(,)? }) => {
         paste! {
             static mut [<$symbol _PLUGIN_DESC>]: dmconfigfile::Desc = [0u8; dmconfigfile::DESC_BUFFER_SIZE as usize];
 
@@ -272,6 +902,103 @@ macro_rules! declare_configfile_extension {
                     [<$symbol _plugin_get_int>],
                     [<$symbol _plugin_get_float>],
                 );
+
+                let mut info = dmconfigfile::PluginInfo::new(stringify!($symbol));
+                $( info.$field = ($value).to_owned(); )*
+                dmconfigfile::record_plugin_info(info);
+            }
+        }
+    };
+}
+
+/// Context-carrying variant of [`declare_configfile_extension`].
+///
+/// Instead of forcing plugin authors into `static mut` globals, `plugin_create`
+/// returns a user-defined `Box<T>` which is stored beside the descriptor. Every
+/// getter and the `plugin_destroy` callback then receive `&mut T` as their first
+/// argument, letting a plugin keep parsed state (caches, file handles, regexes)
+/// without unsafe globals. The context pointer lives in a single static owned by
+/// the macro, so there is one context per plugin, not one per config handle. The
+/// context type is named explicitly so the generated shims can recover it when
+/// recasting the stored pointer.
+///
+/// # Examples
+/// ```
+/// # const LOG_DOMAIN: &str = "DOCTEST";
+/// use dmsdk::*;
+///
+/// #[derive(Default)]
+/// struct Context {
+///     hits: u32,
+/// }
+///
+/// fn plugin_create(config: dmconfigfile::ConfigFile) -> Box<Context> {
+///     Box::new(Context::default())
+/// }
+///
+/// fn plugin_destroy(context: &mut Context, config: dmconfigfile::ConfigFile) {
+///     dmlog::info!("Plugin served {} keys", context.hits);
+/// }
+///
+/// fn get_string(context: &mut Context, config: dmconfigfile::ConfigFile, key: &str, default_value: &str) -> Option<String> {
+///     context.hits += 1;
+///     None
+/// }
+///
+/// declare_configfile_extension_with_context!(
+///     MY_CONFIG_PLUGIN,
+///     Context,
+///     plugin_create,
+///     Some(plugin_destroy),
+///     Some(get_string),
+///     None,
+///     None
+/// );
+/// ```
+#[macro_export]
+macro_rules! declare_configfile_extension_with_context {
+    ($symbol:ident, $context:ty, $create:expr, $destroy:expr, $get_string:expr, $get_int:expr, $get_float:expr) => {
+        paste! {
+            static mut [<$symbol _PLUGIN_DESC>]: dmconfigfile::Desc = [0u8; dmconfigfile::DESC_BUFFER_SIZE as usize];
+            static [<$symbol _CONTEXT>]: core::sync::atomic::AtomicPtr<core::ffi::c_void> =
+                core::sync::atomic::AtomicPtr::new(core::ptr::null_mut());
+
+            #[no_mangle]
+            unsafe extern "C" fn [<$symbol _plugin_create>](config: dmconfigfile::RawConfigFile) {
+                let create: dmconfigfile::PluginCreateCtx<$context> = $create;
+                let ptr = Box::into_raw(create(config.into()));
+                [<$symbol _CONTEXT>].store(ptr as *mut core::ffi::c_void, core::sync::atomic::Ordering::SeqCst);
+            }
+
+            #[no_mangle]
+            unsafe extern "C" fn [<$symbol _plugin_destroy>](config: dmconfigfile::RawConfigFile) {
+                let destroy: Option<dmconfigfile::PluginLifecycleCtx<$context>> = $destroy;
+                let ptr = [<$symbol _CONTEXT>].swap(core::ptr::null_mut(), core::sync::atomic::Ordering::SeqCst);
+                if !ptr.is_null() {
+                    let mut boxed: Box<$context> = Box::from_raw(ptr as *mut $context);
+                    if let Some(destroy) = destroy {
+                        destroy(&mut boxed, config.into());
+                    }
+                }
+            }
+
+            declare_plugin_string_getter_ctx!([<$symbol _plugin_get_string>], [<$symbol _CONTEXT>], $context, $get_string);
+            declare_plugin_getter_ctx!([<$symbol _plugin_get_int>], [<$symbol _CONTEXT>], $context, $get_int, i32);
+            declare_plugin_getter_ctx!([<$symbol _plugin_get_float>], [<$symbol _CONTEXT>], $context, $get_float, f32);
+
+            #[no_mangle]
+            #[dmextension::ctor]
+            unsafe fn $symbol() {
+                dmconfigfile::register(
+                    &mut [<$symbol _PLUGIN_DESC>],
+                    stringify!($symbol),
+                    [<$symbol _plugin_create>],
+                    [<$symbol _plugin_destroy>],
+                    [<$symbol _plugin_get_string>],
+                    [<$symbol _plugin_get_int>],
+                    [<$symbol _plugin_get_float>],
+                );
+                dmconfigfile::record_plugin_info(dmconfigfile::PluginInfo::new(stringify!($symbol)));
             }
         }
     };
@@ -302,5 +1029,61 @@ pub fn register(
     }
 }
 
+/// Name, version and provenance recorded for a registered config plugin.
+///
+/// Populated from the optional metadata block of [`declare_configfile_extension`]
+/// and retrievable through [`registered_plugins`], so a host extension can log
+/// which config plugins are active — useful for diagnosing which plugin overrode a
+/// given key when several are chained.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PluginInfo {
+    /// Symbol name of the plugin.
+    pub name: String,
+    /// Version string, or empty if the plugin didn't supply one.
+    pub version: String,
+    /// License identifier, or empty if unspecified.
+    pub license: String,
+    /// Human-readable description, or empty if unspecified.
+    pub description: String,
+}
+
+impl PluginInfo {
+    /// Creates a [`PluginInfo`] with the given name and empty provenance fields.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            ..Self::default()
+        }
+    }
+}
+
+static PLUGIN_REGISTRY: Mutex<Vec<PluginInfo>> = Mutex::new(Vec::new());
+
+#[doc(hidden)]
+pub fn record_plugin_info(info: PluginInfo) {
+    PLUGIN_REGISTRY.lock().unwrap().push(info);
+}
+
+/// Returns metadata for every config plugin registered so far.
+///
+/// # Examples
+/// ```
+/// # const LOG_DOMAIN: &str = "DOCTEST";
+/// use dmsdk::*;
+///
+/// fn app_init(params: dmextension::AppParams) -> dmextension::Result {
+///     for plugin in dmconfigfile::registered_plugins() {
+///         dmlog::info!("Config plugin '{}' v{}", plugin.name, plugin.version);
+///     }
+///
+///     dmextension::Result::Ok
+/// }
+/// ```
+pub fn registered_plugins() -> Vec<PluginInfo> {
+    PLUGIN_REGISTRY.lock().unwrap().clone()
+}
+
 #[doc(inline)]
 pub use crate::declare_configfile_extension;
+#[doc(inline)]
+pub use crate::declare_configfile_extension_with_context;